@@ -1,13 +1,118 @@
-use crate::{Toast, Workspace};
+use crate::Workspace;
 use collections::HashMap;
 use gpui::{
     AnyView, AppContext, AsyncWindowContext, DismissEvent, Entity, EntityId, EventEmitter, Render,
-    Task, View, ViewContext, VisualContext, WindowContext,
+    SharedString, Task, View, ViewContext, VisualContext, WindowContext,
 };
-use std::{any::TypeId, ops::DerefMut};
+use std::{any::TypeId, collections::VecDeque, ops::DerefMut, sync::Arc, time::Instant};
+
+pub use desktop_notifications::{DesktopAction, DesktopNotifier, Urgency};
+pub use simple_message_notification::Severity;
+
+/// How noisy a notification type or category is allowed to be.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationLevel {
+    /// Shown in-window; a `MessageNotification` is also sent to the desktop
+    /// notifier while the window is inactive.
+    Enabled,
+    /// Shown in-window only.
+    Silent,
+    Muted,
+}
+
+impl Default for NotificationLevel {
+    fn default() -> Self {
+        Self::Enabled
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum NotificationKey {
+    Type(TypeId),
+    Category(SharedString),
+}
+
+/// Per-type and per-category notification preferences; a category
+/// overrides its type's setting when present.
+#[derive(Default)]
+pub struct NotificationSettings {
+    levels: HashMap<NotificationKey, NotificationLevel>,
+}
+
+impl NotificationSettings {
+    pub fn set_type_level(&mut self, type_id: TypeId, level: NotificationLevel) {
+        self.levels.insert(NotificationKey::Type(type_id), level);
+    }
+
+    pub fn set_category_level(
+        &mut self,
+        category: impl Into<SharedString>,
+        level: NotificationLevel,
+    ) {
+        self.levels
+            .insert(NotificationKey::Category(category.into()), level);
+    }
+
+    fn level_for(&self, type_id: TypeId, category: Option<&SharedString>) -> NotificationLevel {
+        if let Some(category) = category {
+            if let Some(level) = self.levels.get(&NotificationKey::Category(category.clone())) {
+                return *level;
+            }
+        }
+        self.levels
+            .get(&NotificationKey::Type(type_id))
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+pub fn set_notification_type_level<V: Notification>(cx: &mut AppContext, level: NotificationLevel) {
+    cx.global_mut::<NotificationTracker>()
+        .settings
+        .set_type_level(TypeId::of::<V>(), level);
+}
+
+pub fn set_notification_category_level(
+    cx: &mut AppContext,
+    category: impl Into<SharedString>,
+    level: NotificationLevel,
+) {
+    cx.global_mut::<NotificationTracker>()
+        .settings
+        .set_category_level(category, level);
+}
 
 pub fn init(cx: &mut AppContext) {
     cx.set_global(NotificationTracker::new());
+    set_desktop_notifier(cx, default_desktop_notifier());
+}
+
+fn default_desktop_notifier() -> Arc<dyn DesktopNotifier> {
+    #[cfg(target_os = "linux")]
+    {
+        Arc::new(desktop_notifications::LinuxNotifySendNotifier)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        Arc::new(desktop_notifications::NullDesktopNotifier)
+    }
+}
+
+pub fn set_desktop_notifier(cx: &mut AppContext, notifier: Arc<dyn DesktopNotifier>) {
+    cx.set_global(DesktopNotifierHandle(notifier));
+}
+
+struct DesktopNotifierHandle(Arc<dyn DesktopNotifier>);
+
+/// Reserves the top bit so coalesced summary ids never collide with a real
+/// (small, caller-supplied) notification id.
+const COALESCED_SUMMARY_ID_BIT: usize = 1 << (usize::BITS - 1);
+
+fn coalesced_summary_id(type_id: TypeId) -> usize {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    type_id.hash(&mut hasher);
+    (hasher.finish() as usize) | COALESCED_SUMMARY_ID_BIT
 }
 
 pub trait Notification: EventEmitter<DismissEvent> + Render {}
@@ -37,6 +142,20 @@ impl From<&dyn NotificationHandle> for AnyView {
 
 pub(crate) struct NotificationTracker {
     notifications_sent: HashMap<TypeId, Vec<usize>>,
+    rate_limits: HashMap<TypeId, RateLimitState>,
+    settings: NotificationSettings,
+    history: VecDeque<NotificationHistoryEntry>,
+}
+
+/// A past notification, kept around for the history panel.
+pub(crate) struct NotificationHistoryEntry {
+    type_id: TypeId,
+    id: usize,
+    category: Option<SharedString>,
+    message: SharedString,
+    had_action: bool,
+    shown_at: Instant,
+    replay: Option<View<simple_message_notification::MessageNotification>>,
 }
 
 impl std::ops::Deref for NotificationTracker {
@@ -53,12 +172,121 @@ impl DerefMut for NotificationTracker {
     }
 }
 
+/// Token bucket state for a single notification type.
+struct RateLimitState {
+    tokens: f64,
+    last_refill: Instant,
+    suppressed: usize,
+}
+
+struct RateLimitDecision {
+    allowed: bool,
+    /// A run of suppressed notifications just ended and this many should be
+    /// reported as a single coalesced summary.
+    flushed_suppressed_count: Option<usize>,
+}
+
 impl NotificationTracker {
+    const RATE_LIMIT_PER_SECOND: f64 = 2.0;
+    const RATE_LIMIT_BURST: f64 = 3.0;
+    const HISTORY_CAPACITY: usize = 100;
+
     fn new() -> Self {
         Self {
             notifications_sent: Default::default(),
+            rate_limits: Default::default(),
+            settings: Default::default(),
+            history: Default::default(),
+        }
+    }
+
+    fn record_history(&mut self, entry: NotificationHistoryEntry) {
+        self.history.push_back(entry);
+        if self.history.len() > Self::HISTORY_CAPACITY {
+            self.history.pop_front();
+        }
+    }
+
+    /// Mutes a past history entry's `type_id`/`category`. Prefers the
+    /// category when the entry has one, since several notification kinds
+    /// share a concrete view type.
+    pub(crate) fn mute(&mut self, type_id: TypeId, category: Option<SharedString>) {
+        match category {
+            Some(category) => self.settings.set_category_level(category, NotificationLevel::Muted),
+            None => self.settings.set_type_level(type_id, NotificationLevel::Muted),
+        }
+    }
+
+    fn level_for(&self, type_id: TypeId, category: Option<&SharedString>) -> NotificationLevel {
+        self.settings.level_for(type_id, category)
+    }
+
+    fn check_rate_limit(&mut self, type_id: TypeId) -> RateLimitDecision {
+        let now = Instant::now();
+        let state = self
+            .rate_limits
+            .entry(type_id)
+            .or_insert_with(|| RateLimitState {
+                tokens: Self::RATE_LIMIT_BURST,
+                last_refill: now,
+                suppressed: 0,
+            });
+
+        let elapsed = now.duration_since(state.last_refill).as_secs_f64();
+        state.tokens =
+            (state.tokens + elapsed * Self::RATE_LIMIT_PER_SECOND).min(Self::RATE_LIMIT_BURST);
+        state.last_refill = now;
+
+        if state.tokens >= 1.0 {
+            state.tokens -= 1.0;
+            RateLimitDecision {
+                allowed: true,
+                flushed_suppressed_count: (state.suppressed > 0)
+                    .then(|| std::mem::take(&mut state.suppressed)),
+            }
+        } else {
+            state.suppressed += 1;
+            RateLimitDecision {
+                allowed: false,
+                flushed_suppressed_count: None,
+            }
+        }
+    }
+}
+
+/// Shown via [`Workspace::show_toast`], addressed by `id` for a later
+/// [`Workspace::dismiss_toast`] call. Re-exported as `crate::Toast`.
+pub struct Toast {
+    id: usize,
+    msg: SharedString,
+    severity: Severity,
+    actions: Vec<(SharedString, Arc<dyn Fn(&mut WindowContext)>)>,
+}
+
+impl Toast {
+    pub fn new(id: usize, msg: impl Into<SharedString>) -> Self {
+        Self {
+            id,
+            msg: msg.into(),
+            severity: Severity::Info,
+            actions: Vec::new(),
         }
     }
+
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Adds a button labeled `label` that runs `handler` when clicked, then
+    /// dismisses the toast. Actions render in the order they were added.
+    pub fn with_action<S>(mut self, label: S, handler: impl Fn(&mut WindowContext) + 'static) -> Self
+    where
+        S: Into<SharedString>,
+    {
+        self.actions.push((label.into(), Arc::new(handler)));
+        self
+    }
 }
 
 impl Workspace {
@@ -73,18 +301,34 @@ impl Workspace {
             .unwrap_or(false)
     }
 
+    /// Like [`Self::show_notification`], but only ever shows a given `id`
+    /// once per `V`. Bypasses the rate limiter.
     pub fn show_notification_once<V: Notification>(
         &mut self,
         id: usize,
         cx: &mut ViewContext<Self>,
         build_notification: impl FnOnce(&mut ViewContext<Self>) -> View<V>,
     ) {
-        if !self.has_shown_notification_once::<V>(id, cx) {
-            let tracker = cx.global_mut::<NotificationTracker>();
-            let entry = tracker.entry(TypeId::of::<V>()).or_default();
-            entry.push(id);
-            self.show_notification::<V>(id, cx, build_notification)
+        if self.has_shown_notification_once::<V>(id, cx) {
+            return;
+        }
+
+        let type_id = TypeId::of::<V>();
+        let level = cx.global::<NotificationTracker>().level_for(type_id, None);
+        if level == NotificationLevel::Muted {
+            return;
         }
+
+        let tracker = cx.global_mut::<NotificationTracker>();
+        let entry = tracker.entry(type_id).or_default();
+        entry.push(id);
+        self.show_notification_unthrottled(
+            id,
+            None,
+            level == NotificationLevel::Silent,
+            cx,
+            build_notification,
+        )
     }
 
     pub fn show_notification<V: Notification>(
@@ -92,6 +336,54 @@ impl Workspace {
         id: usize,
         cx: &mut ViewContext<Self>,
         build_notification: impl FnOnce(&mut ViewContext<Self>) -> View<V>,
+    ) {
+        self.show_categorized_notification(id, None, cx, build_notification)
+    }
+
+    /// Like [`Self::show_notification`], but tagged with a named category
+    /// that `NotificationSettings` can target independently of `V`.
+    pub fn show_categorized_notification<V: Notification>(
+        &mut self,
+        id: usize,
+        category: Option<SharedString>,
+        cx: &mut ViewContext<Self>,
+        build_notification: impl FnOnce(&mut ViewContext<Self>) -> View<V>,
+    ) {
+        let type_id = TypeId::of::<V>();
+        let level = cx
+            .global::<NotificationTracker>()
+            .level_for(type_id, category.as_ref());
+        if level == NotificationLevel::Muted {
+            return;
+        }
+
+        let decision = cx.global_mut::<NotificationTracker>().check_rate_limit(type_id);
+        if let Some(suppressed) = decision.flushed_suppressed_count {
+            self.show_coalesced_summary(type_id, suppressed, cx);
+        }
+        if !decision.allowed {
+            return;
+        }
+
+        self.show_notification_unthrottled(
+            id,
+            category,
+            level == NotificationLevel::Silent,
+            cx,
+            build_notification,
+        )
+    }
+
+    /// Shows a notification without consulting the rate limiter.
+    /// `suppress_click_through` downgrades it to in-window only, skipping
+    /// the desktop fan-out.
+    fn show_notification_unthrottled<V: Notification>(
+        &mut self,
+        id: usize,
+        category: Option<SharedString>,
+        suppress_click_through: bool,
+        cx: &mut ViewContext<Self>,
+        build_notification: impl FnOnce(&mut ViewContext<Self>) -> View<V>,
     ) {
         let type_id = TypeId::of::<V>();
         if self
@@ -106,12 +398,87 @@ impl Workspace {
                 this.dismiss_notification_internal(type_id, id, cx);
             })
             .detach();
+
+            let message_notification = notification
+                .to_any()
+                .downcast::<simple_message_notification::MessageNotification>()
+                .ok();
+
+            // Only fan out to the OS notification center when the window
+            // isn't in front of the user, otherwise the in-window toast
+            // already does the job.
+            if !suppress_click_through && !cx.is_window_active() {
+                if let Some(message_notification) = message_notification.clone() {
+                    message_notification.update(cx, |notification, cx| {
+                        notification.notify_desktop(cx);
+                    });
+                }
+            }
+
+            // Every notification gets a history entry, not just
+            // `MessageNotification`-backed ones. The history panel itself is
+            // excluded so opening it doesn't record itself.
+            if type_id != TypeId::of::<notification_history::NotificationHistoryPanel>() {
+                let entry = match &message_notification {
+                    Some(message_notification) => {
+                        let message_notification_ref = message_notification.read(cx);
+                        NotificationHistoryEntry {
+                            type_id,
+                            id,
+                            category: category.clone(),
+                            message: message_notification_ref.message.clone(),
+                            had_action: !message_notification_ref.actions.is_empty(),
+                            shown_at: Instant::now(),
+                            replay: Some(message_notification.clone()),
+                        }
+                    }
+                    None => NotificationHistoryEntry {
+                        type_id,
+                        id,
+                        category: category.clone(),
+                        message: std::any::type_name::<V>().into(),
+                        had_action: false,
+                        shown_at: Instant::now(),
+                        replay: None,
+                    },
+                };
+                cx.global_mut::<NotificationTracker>().record_history(entry);
+            }
+
             self.notifications
                 .push((type_id, id, Box::new(notification)));
             cx.notify();
         }
     }
 
+    /// Reports a run of suppressed notifications as a single summary, keyed
+    /// off the suppressed type so a later run replaces rather than stacks.
+    fn show_coalesced_summary(
+        &mut self,
+        type_id: TypeId,
+        suppressed_count: usize,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let id = coalesced_summary_id(type_id);
+        let suppress_click_through =
+            cx.global::<NotificationTracker>().level_for(type_id, None) == NotificationLevel::Silent;
+        let workspace = cx.view().downgrade();
+        self.dismiss_notification::<simple_message_notification::MessageNotification>(id, cx);
+        self.show_notification_unthrottled(id, None, suppress_click_through, cx, |cx| {
+            cx.new_view(|_cx| {
+                simple_message_notification::MessageNotification::new(format!(
+                    "{suppressed_count} more notifications suppressed"
+                ))
+                .with_click_message("View All")
+                .on_click(move |cx| {
+                    workspace
+                        .update(cx, |workspace, cx| workspace.toggle_notification_history(cx))
+                        .ok();
+                })
+            })
+        });
+    }
+
     pub fn show_error<E>(&mut self, err: &E, cx: &mut ViewContext<Self>)
     where
         E: std::fmt::Debug,
@@ -119,6 +486,7 @@ impl Workspace {
         self.show_notification(0, cx, |cx| {
             cx.new_view(|_cx| {
                 simple_message_notification::MessageNotification::new(format!("Error: {err:?}"))
+                    .with_severity(Severity::Error)
             })
         });
     }
@@ -132,14 +500,15 @@ impl Workspace {
     pub fn show_toast(&mut self, toast: Toast, cx: &mut ViewContext<Self>) {
         self.dismiss_notification::<simple_message_notification::MessageNotification>(toast.id, cx);
         self.show_notification(toast.id, cx, |cx| {
-            cx.new_view(|_cx| match toast.on_click.as_ref() {
-                Some((click_msg, on_click)) => {
-                    let on_click = on_click.clone();
+            cx.new_view(|_cx| {
+                let mut notification =
                     simple_message_notification::MessageNotification::new(toast.msg.clone())
-                        .with_click_message(click_msg.clone())
-                        .on_click(move |cx| on_click(cx))
+                        .with_severity(toast.severity);
+                for (label, handler) in &toast.actions {
+                    let handler = handler.clone();
+                    notification = notification.with_action(label.clone(), move |cx| handler(cx));
                 }
-                None => simple_message_notification::MessageNotification::new(toast.msg.clone()),
+                notification
             })
         })
     }
@@ -148,6 +517,28 @@ impl Workspace {
         self.dismiss_notification::<simple_message_notification::MessageNotification>(id, cx);
     }
 
+    /// Shows or hides the notification history panel.
+    pub fn toggle_notification_history(&mut self, cx: &mut ViewContext<Self>) {
+        const HISTORY_NOTIFICATION_ID: usize = 0;
+        let type_id = TypeId::of::<notification_history::NotificationHistoryPanel>();
+        let is_open = self
+            .notifications
+            .iter()
+            .any(|(existing_type_id, existing_id, _)| {
+                (*existing_type_id, *existing_id) == (type_id, HISTORY_NOTIFICATION_ID)
+            });
+        if is_open {
+            self.dismiss_notification::<notification_history::NotificationHistoryPanel>(
+                HISTORY_NOTIFICATION_ID,
+                cx,
+            );
+        } else {
+            self.show_notification(HISTORY_NOTIFICATION_ID, cx, |cx| {
+                cx.new_view(|_cx| notification_history::NotificationHistoryPanel)
+            });
+        }
+    }
+
     fn dismiss_notification_internal(
         &mut self,
         type_id: TypeId,
@@ -169,16 +560,51 @@ impl Workspace {
 pub mod simple_message_notification {
     use gpui::{
         div, DismissEvent, EventEmitter, InteractiveElement, ParentElement, Render, SharedString,
-        StatefulInteractiveElement, Styled, ViewContext,
+        StatefulInteractiveElement, Styled, Task, ViewContext,
+    };
+    use std::{
+        sync::Arc,
+        time::{Duration, Instant},
     };
-    use std::sync::Arc;
     use ui::prelude::*;
-    use ui::{h_flex, v_flex, Button, Icon, IconName, Label, StyledExt};
+    use ui::{h_flex, v_flex, Button, Color, Icon, IconName, Label, StyledExt};
+
+    /// How insistently a notification should be presented, controlling its
+    /// icon/accent color and whether it auto-dismisses.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Severity {
+        Info,
+        Warning,
+        Error,
+        Critical,
+    }
+
+    impl Severity {
+        fn icon(&self) -> IconName {
+            match self {
+                Severity::Info => IconName::Info,
+                Severity::Warning => IconName::Warning,
+                Severity::Error | Severity::Critical => IconName::XCircle,
+            }
+        }
+
+        fn color(&self) -> Color {
+            match self {
+                Severity::Info => Color::Info,
+                Severity::Warning => Color::Warning,
+                Severity::Error | Severity::Critical => Color::Error,
+            }
+        }
+    }
 
     pub struct MessageNotification {
-        message: SharedString,
-        on_click: Option<Arc<dyn Fn(&mut ViewContext<Self>)>>,
-        click_message: Option<SharedString>,
+        pub(super) message: SharedString,
+        pub(super) actions: Vec<(SharedString, Arc<dyn Fn(&mut ViewContext<Self>)>)>,
+        severity: Severity,
+        auto_dismiss_remaining: Option<Duration>,
+        dismiss_timer_started_at: Option<Instant>,
+        dismiss_timer: Option<Task<()>>,
+        hovered: bool,
     }
 
     impl EventEmitter<DismissEvent> for MessageNotification {}
@@ -190,41 +616,168 @@ pub mod simple_message_notification {
         {
             Self {
                 message: message.into(),
-                on_click: None,
-                click_message: None,
+                actions: Vec::new(),
+                severity: Severity::Info,
+                auto_dismiss_remaining: None,
+                dismiss_timer_started_at: None,
+                dismiss_timer: None,
+                hovered: false,
             }
         }
 
-        pub fn with_click_message<S>(mut self, message: S) -> Self
+        /// Adds a button labeled `label` that runs `handler` when clicked,
+        /// then dismisses the notification.
+        pub fn with_action<S, F>(mut self, label: S, handler: F) -> Self
         where
             S: Into<SharedString>,
+            F: 'static + Fn(&mut ViewContext<Self>),
         {
-            self.click_message = Some(message.into());
+            self.actions.push((label.into(), Arc::new(handler)));
             self
         }
 
-        pub fn on_click<F>(mut self, on_click: F) -> Self
+        /// Pairs with [`Self::on_click`]: `with_click_message(label).on_click(handler)`.
+        pub fn with_click_message<S>(self, label: S) -> Self
+        where
+            S: Into<SharedString>,
+        {
+            self.with_action(label, |_| {})
+        }
+
+        /// Sets the handler for the most recently added action.
+        pub fn on_click<F>(mut self, handler: F) -> Self
         where
             F: 'static + Fn(&mut ViewContext<Self>),
         {
-            self.on_click = Some(Arc::new(on_click));
+            if let Some(last) = self.actions.last_mut() {
+                last.1 = Arc::new(handler);
+            }
+            self
+        }
+
+        pub fn with_severity(mut self, severity: Severity) -> Self {
+            self.severity = severity;
+            self
+        }
+
+        /// Auto-dismisses after `after` elapses; has no effect for
+        /// `Severity::Critical`.
+        pub fn with_auto_dismiss(mut self, after: Duration) -> Self {
+            self.auto_dismiss_remaining = Some(after);
             self
         }
 
         pub fn dismiss(&mut self, cx: &mut ViewContext<Self>) {
             cx.emit(DismissEvent);
         }
+
+        /// (Re)starts the auto-dismiss timer; a no-op while hovered or
+        /// already running.
+        fn resume_dismiss_timer(&mut self, cx: &mut ViewContext<Self>) {
+            if self.hovered || self.dismiss_timer.is_some() || self.severity == Severity::Critical
+            {
+                return;
+            }
+            let Some(remaining) = self.auto_dismiss_remaining else {
+                return;
+            };
+            self.dismiss_timer_started_at = Some(Instant::now());
+            let timer = cx.background_executor().timer(remaining);
+            self.dismiss_timer = Some(cx.spawn(|this, mut cx| async move {
+                timer.await;
+                this.update(&mut cx, |this, cx| this.dismiss(cx)).ok();
+            }));
+        }
+
+        /// Cancels the pending timer, remembering the time left.
+        fn pause_dismiss_timer(&mut self) {
+            if let Some(started_at) = self.dismiss_timer_started_at.take() {
+                if let Some(remaining) = self.auto_dismiss_remaining {
+                    self.auto_dismiss_remaining = Some(remaining.saturating_sub(started_at.elapsed()));
+                }
+            }
+            self.dismiss_timer = None;
+        }
+
+        /// Re-invokes the first action without dismissing, for the history
+        /// panel's "Replay" button.
+        pub(crate) fn replay(&self, cx: &mut ViewContext<Self>) {
+            if let Some((_, handler)) = self.actions.first() {
+                let handler = handler.clone();
+                handler(cx);
+            }
+        }
+
+        /// Forwards this notification to the OS notification center, if one
+        /// is registered.
+        pub(crate) fn notify_desktop(&mut self, cx: &mut ViewContext<Self>) {
+            let Some(notifier) = cx.try_global::<super::DesktopNotifierHandle>() else {
+                return;
+            };
+            let notifier = notifier.0.clone();
+            let desktop_actions = self
+                .actions
+                .iter()
+                .enumerate()
+                .map(|(index, (label, _))| super::DesktopAction {
+                    key: index.to_string().into(),
+                    label: label.clone(),
+                })
+                .collect::<Vec<_>>();
+            let urgency = match self.severity {
+                Severity::Info | Severity::Warning => super::Urgency::Low,
+                Severity::Error => super::Urgency::Normal,
+                Severity::Critical => super::Urgency::Critical,
+            };
+            let handle = cx.view().downgrade();
+            let mut cx = cx.to_async();
+            notifier.notify(
+                "Zed",
+                &self.message,
+                urgency,
+                &desktop_actions,
+                Box::new(move |action_key| {
+                    let index = action_key.parse::<usize>().ok();
+                    handle
+                        .update(&mut cx, |this, cx| {
+                            if let Some(handler) =
+                                index.and_then(|index| this.actions.get(index)).map(|(_, handler)| handler.clone())
+                            {
+                                handler(cx);
+                            }
+                            this.dismiss(cx);
+                        })
+                        .ok();
+                }),
+            );
+        }
     }
 
     impl Render for MessageNotification {
         fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+            self.resume_dismiss_timer(cx);
+
             v_flex()
+                .id("message-notification")
                 .elevation_3(cx)
                 .p_4()
+                .on_hover(cx.listener(|this, hovered, cx| {
+                    this.hovered = *hovered;
+                    if this.hovered {
+                        this.pause_dismiss_timer();
+                    } else {
+                        this.resume_dismiss_timer(cx);
+                    }
+                }))
                 .child(
                     h_flex()
                         .justify_between()
-                        .child(div().max_w_80().child(Label::new(self.message.clone())))
+                        .child(
+                            h_flex()
+                                .gap_2()
+                                .child(Icon::new(self.severity.icon()).color(self.severity.color()))
+                                .child(div().max_w_80().child(Label::new(self.message.clone()))),
+                        )
                         .child(
                             div()
                                 .id("cancel")
@@ -233,20 +786,118 @@ pub mod simple_message_notification {
                                 .on_click(cx.listener(|this, _, cx| this.dismiss(cx))),
                         ),
                 )
-                .children(self.click_message.iter().map(|message| {
-                    Button::new(message.clone(), message.clone()).on_click(cx.listener(
-                        |this, _, cx| {
-                            if let Some(on_click) = this.on_click.as_ref() {
-                                (on_click)(cx)
-                            };
+                .children(self.actions.iter().enumerate().map(|(index, (label, _))| {
+                    Button::new(SharedString::from(format!("notification-action-{index}")), label.clone())
+                        .on_click(cx.listener(move |this, _, cx| {
+                            if let Some((_, handler)) = this.actions.get(index) {
+                                let handler = handler.clone();
+                                handler(cx);
+                            }
                             this.dismiss(cx)
-                        },
-                    ))
+                        }))
                 }))
         }
     }
 }
 
+/// Renders the log of past notifications kept by [`NotificationTracker`].
+pub mod notification_history {
+    use super::NotificationTracker;
+    use gpui::{
+        div, DismissEvent, EventEmitter, InteractiveElement, ParentElement, Render, SharedString,
+        StatefulInteractiveElement, Styled, ViewContext,
+    };
+    use ui::prelude::*;
+    use ui::{h_flex, v_flex, Button, Icon, IconName, Label, StyledExt};
+
+    fn time_ago(elapsed: std::time::Duration) -> String {
+        let secs = elapsed.as_secs();
+        if secs < 60 {
+            format!("{secs}s ago")
+        } else if secs < 60 * 60 {
+            format!("{}m ago", secs / 60)
+        } else {
+            format!("{}h ago", secs / 60 / 60)
+        }
+    }
+
+    pub struct NotificationHistoryPanel;
+
+    impl EventEmitter<DismissEvent> for NotificationHistoryPanel {}
+
+    impl NotificationHistoryPanel {
+        fn dismiss(&mut self, cx: &mut ViewContext<Self>) {
+            cx.emit(DismissEvent);
+        }
+
+        fn clear_history(&mut self, cx: &mut ViewContext<Self>) {
+            cx.global_mut::<NotificationTracker>().history.clear();
+            cx.notify();
+        }
+    }
+
+    impl Render for NotificationHistoryPanel {
+        fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+            let history = &cx.global::<NotificationTracker>().history;
+
+            v_flex()
+                .elevation_3(cx)
+                .p_4()
+                .max_h_96()
+                .child(
+                    h_flex()
+                        .justify_between()
+                        .child(Label::new("Notification History"))
+                        .child(
+                            div()
+                                .id("cancel")
+                                .child(Icon::new(IconName::Close))
+                                .cursor_pointer()
+                                .on_click(cx.listener(|this, _, cx| this.dismiss(cx))),
+                        ),
+                )
+                .child(
+                    v_flex()
+                        .overflow_y_scroll()
+                        .children(history.iter().map(|entry| {
+                            let type_id = entry.type_id;
+                            let id = entry.id;
+                            let category = entry.category.clone();
+                            h_flex()
+                                .justify_between()
+                                .child(div().max_w_80().child(Label::new(entry.message.clone())))
+                                .child(Label::new(time_ago(entry.shown_at.elapsed())))
+                                .children(entry.replay.clone().filter(|_| entry.had_action).map(
+                                    |replay| {
+                                        Button::new(
+                                            SharedString::from(format!("replay-{id}")),
+                                            "Replay",
+                                        )
+                                        .on_click(move |_, cx| {
+                                            replay.update(cx, |notification, cx| {
+                                                notification.replay(cx);
+                                            });
+                                        })
+                                    },
+                                ))
+                                .child(
+                                    Button::new(SharedString::from(format!("mute-{id}")), "Mute")
+                                        .on_click(move |_, cx| {
+                                            cx.global_mut::<NotificationTracker>()
+                                                .mute(type_id, category.clone());
+                                        }),
+                                )
+                        })),
+                )
+                .child(
+                    Button::new("clear-history", "Clear").on_click(
+                        cx.listener(|this, _, cx| this.clear_history(cx)),
+                    ),
+                )
+        }
+    }
+}
+
 pub trait NotifyResultExt {
     type Ok;
 
@@ -307,3 +958,245 @@ where
             .detach();
     }
 }
+
+/// Abstraction over the platform's native notification center.
+pub mod desktop_notifications {
+    use gpui::SharedString;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Urgency {
+        Low,
+        Normal,
+        Critical,
+    }
+
+    pub struct DesktopAction {
+        pub key: SharedString,
+        pub label: SharedString,
+    }
+
+    /// Sends a single notification to the OS. `on_action` is invoked with
+    /// the key of whichever action the user picked.
+    pub trait DesktopNotifier: Send + Sync {
+        fn notify(
+            &self,
+            summary: &str,
+            body: &str,
+            urgency: Urgency,
+            actions: &[DesktopAction],
+            on_action: Box<dyn FnOnce(&str) + Send>,
+        );
+    }
+
+    /// Fallback used when no platform backend is registered.
+    pub struct NullDesktopNotifier;
+
+    impl DesktopNotifier for NullDesktopNotifier {
+        fn notify(
+            &self,
+            _summary: &str,
+            _body: &str,
+            _urgency: Urgency,
+            _actions: &[DesktopAction],
+            _on_action: Box<dyn FnOnce(&str) + Send>,
+        ) {
+        }
+    }
+
+    /// Linux backend built on the `notify-send` CLI.
+    pub struct LinuxNotifySendNotifier;
+
+    impl DesktopNotifier for LinuxNotifySendNotifier {
+        fn notify(
+            &self,
+            summary: &str,
+            body: &str,
+            urgency: Urgency,
+            actions: &[DesktopAction],
+            on_action: Box<dyn FnOnce(&str) + Send>,
+        ) {
+            let urgency = match urgency {
+                Urgency::Low => "low",
+                Urgency::Normal => "normal",
+                Urgency::Critical => "critical",
+            };
+
+            let mut command = std::process::Command::new("notify-send");
+            command
+                .arg("--app-name=Zed")
+                .arg("--urgency")
+                .arg(urgency)
+                .arg("--wait")
+                .arg(summary)
+                .arg(body);
+            for action in actions {
+                command
+                    .arg("--action")
+                    .arg(format!("{}={}", action.key, action.label));
+            }
+
+            std::thread::spawn(move || {
+                let Ok(output) = command.output() else {
+                    return;
+                };
+                let chosen = String::from_utf8_lossy(&output.stdout);
+                let chosen = chosen.trim();
+                if !chosen.is_empty() {
+                    on_action(chosen);
+                }
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    struct SomeNotification;
+    struct OtherNotification;
+
+    #[test]
+    fn check_rate_limit_allows_burst_then_suppresses_and_flushes() {
+        let mut tracker = NotificationTracker::new();
+        let type_id = TypeId::of::<SomeNotification>();
+
+        for _ in 0..NotificationTracker::RATE_LIMIT_BURST as usize {
+            let decision = tracker.check_rate_limit(type_id);
+            assert!(decision.allowed);
+            assert_eq!(decision.flushed_suppressed_count, None);
+        }
+
+        // Burst is spent: further calls are suppressed, not allowed.
+        let suppressed = tracker.check_rate_limit(type_id);
+        assert!(!suppressed.allowed);
+        assert_eq!(suppressed.flushed_suppressed_count, None);
+        let suppressed_again = tracker.check_rate_limit(type_id);
+        assert!(!suppressed_again.allowed);
+
+        // Once tokens refill, the run of suppressed notifications flushes as
+        // a single coalesced count alongside the next allowed one.
+        if let Some(state) = tracker.rate_limits.get_mut(&type_id) {
+            state.last_refill -= Duration::from_secs(1);
+        }
+        let flushed = tracker.check_rate_limit(type_id);
+        assert!(flushed.allowed);
+        assert_eq!(flushed.flushed_suppressed_count, Some(2));
+    }
+
+    #[test]
+    fn check_rate_limit_tracks_each_type_independently() {
+        let mut tracker = NotificationTracker::new();
+        let some_type = TypeId::of::<SomeNotification>();
+        let other_type = TypeId::of::<OtherNotification>();
+
+        for _ in 0..NotificationTracker::RATE_LIMIT_BURST as usize {
+            assert!(tracker.check_rate_limit(some_type).allowed);
+        }
+        assert!(!tracker.check_rate_limit(some_type).allowed);
+
+        // A burst exhausted for `SomeNotification` shouldn't affect a
+        // different type's own independent bucket.
+        assert!(tracker.check_rate_limit(other_type).allowed);
+    }
+
+    #[test]
+    fn coalesced_summary_id_never_collides_with_a_real_small_id() {
+        let id = coalesced_summary_id(TypeId::of::<SomeNotification>());
+        assert_ne!(id, 0);
+        assert_eq!(id & COALESCED_SUMMARY_ID_BIT, COALESCED_SUMMARY_ID_BIT);
+        // Real ids handed to `show_notification` are small indices/enum
+        // discriminants in practice and never set the top bit.
+        for real_id in 0..1000usize {
+            assert_ne!(real_id | COALESCED_SUMMARY_ID_BIT, real_id);
+        }
+    }
+
+    #[test]
+    fn notification_settings_category_overrides_type_level() {
+        let mut settings = NotificationSettings::default();
+        let type_id = TypeId::of::<SomeNotification>();
+        let category: SharedString = "errors".into();
+
+        assert_eq!(settings.level_for(type_id, None), NotificationLevel::Enabled);
+
+        settings.set_type_level(type_id, NotificationLevel::Silent);
+        assert_eq!(settings.level_for(type_id, None), NotificationLevel::Silent);
+        assert_eq!(
+            settings.level_for(type_id, Some(&category)),
+            NotificationLevel::Silent,
+            "falls back to the type-level setting when no category override exists"
+        );
+
+        settings.set_category_level(category.clone(), NotificationLevel::Muted);
+        assert_eq!(
+            settings.level_for(type_id, Some(&category)),
+            NotificationLevel::Muted,
+            "a category override takes precedence over the type-level setting"
+        );
+        assert_eq!(
+            settings.level_for(type_id, None),
+            NotificationLevel::Silent,
+            "the type-level setting is unaffected by a different category's override"
+        );
+    }
+
+    #[test]
+    fn mute_without_category_mutes_by_type() {
+        let mut tracker = NotificationTracker::new();
+        let type_id = TypeId::of::<SomeNotification>();
+        assert_eq!(tracker.level_for(type_id, None), NotificationLevel::Enabled);
+
+        tracker.mute(type_id, None);
+
+        assert_eq!(tracker.level_for(type_id, None), NotificationLevel::Muted);
+    }
+
+    #[test]
+    fn mute_with_category_only_mutes_that_category() {
+        let mut tracker = NotificationTracker::new();
+        let type_id = TypeId::of::<SomeNotification>();
+        let muted_category: SharedString = "errors".into();
+        let other_category: SharedString = "warnings".into();
+
+        tracker.mute(type_id, Some(muted_category.clone()));
+
+        assert_eq!(
+            tracker.level_for(type_id, Some(&muted_category)),
+            NotificationLevel::Muted
+        );
+        // A different category sharing the same concrete view type is
+        // unaffected, unlike muting by type_id which would silence it too.
+        assert_eq!(
+            tracker.level_for(type_id, Some(&other_category)),
+            NotificationLevel::Enabled
+        );
+        assert_eq!(tracker.level_for(type_id, None), NotificationLevel::Enabled);
+    }
+
+    #[test]
+    fn record_history_evicts_oldest_once_over_capacity() {
+        let mut tracker = NotificationTracker::new();
+        for i in 0..NotificationTracker::HISTORY_CAPACITY + 10 {
+            tracker.record_history(NotificationHistoryEntry {
+                type_id: TypeId::of::<SomeNotification>(),
+                id: i,
+                category: None,
+                message: format!("notification {i}").into(),
+                had_action: false,
+                shown_at: Instant::now(),
+                replay: None,
+            });
+        }
+
+        assert_eq!(tracker.history.len(), NotificationTracker::HISTORY_CAPACITY);
+        // The oldest 10 entries were evicted to make room; arrival order is
+        // preserved for the rest.
+        assert_eq!(tracker.history.front().unwrap().id, 10);
+        assert_eq!(
+            tracker.history.back().unwrap().id,
+            NotificationTracker::HISTORY_CAPACITY + 9
+        );
+    }
+}
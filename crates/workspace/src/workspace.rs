@@ -0,0 +1,3 @@
+// `Toast` used to be defined here; it now lives in `notifications`, next to
+// `Workspace::show_toast`/`dismiss_toast`, which show and dismiss one.
+pub use crate::notifications::Toast;